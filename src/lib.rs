@@ -28,6 +28,30 @@ pub struct LonLatPoint {
     pub lat: f64,
 }
 
+impl LonLatPoint {
+    /**
+     * Encode this point as a pair of fixed-point `GeoCoord`s, for compact,
+     * exactly-comparable storage. Fails if either coordinate falls outside
+     * its valid degree range.
+     */
+    pub fn to_fixed(&self) -> Result<FixedLonLatPoint, CoordRangeError> {
+        Ok(FixedLonLatPoint {
+            lon: GeoCoord::from_degrees(self.lon, 180.0)?,
+            lat: GeoCoord::from_degrees(self.lat, 90.0)?,
+        })
+    }
+
+    /**
+     * Decode a `FixedLonLatPoint` back into a lon/lat point. The inverse of `to_fixed`.
+     */
+    pub fn from_fixed(fixed: FixedLonLatPoint) -> LonLatPoint {
+        LonLatPoint {
+            lon: fixed.lon.to_degrees(180.0),
+            lat: fixed.lat.to_degrees(90.0),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct XYPoint {
     pub x: f64,
@@ -145,10 +169,9 @@ impl SphericalMercator {
      * Convert the tile xyz to a bounding box.
      */
     pub fn bbox(&self, x: u32, y: u32, zoom: u32, tms_style: bool, srs: &str) -> BBox {
-        let mut y = y;
-        if tms_style {
-            y = (2_u32.pow(zoom as u32) - 1) - y;
-        }
+        let tile = Tile { x, y, z: zoom };
+        let tile = if tms_style { tile.flip_y() } else { tile };
+        let y = tile.y;
         let ll = XYPoint {
             x: x as f64 * self.size as f64,
             y: (y as f64 + 1.0) * self.size as f64,
@@ -207,8 +230,20 @@ impl SphericalMercator {
             max_y: y0.max(y1),
         };
         if tms_style {
-            let tms_min_y = (2_u32.pow(zoom as u32) - 1) - bounds.max_y;
-            let tms_max_y = (2_u32.pow(zoom as u32) - 1) - bounds.min_y;
+            let tms_min_y = (Tile {
+                x: 0,
+                y: bounds.max_y,
+                z: zoom,
+            })
+            .flip_y()
+            .y;
+            let tms_max_y = (Tile {
+                x: 0,
+                y: bounds.min_y,
+                z: zoom,
+            })
+            .flip_y()
+            .y;
             bounds.min_y = tms_min_y;
             bounds.max_y = tms_max_y;
         }
@@ -284,6 +319,603 @@ impl SphericalMercator {
             lat: (PI * 0.5 - 2.0 * (-xy.y / A).exp().atan()).to_degrees(),
         }
     }
+
+    /**
+     * Ground resolution (meters per pixel) at the equator for a given zoom level.
+     */
+    pub fn resolution(&self, zoom: f64) -> f64 {
+        let initial_resolution = 2.0 * MAXEXTENT / self.size as f64;
+        initial_resolution / 2.0_f64.powf(zoom)
+    }
+
+    /**
+     * Ground resolution (meters per pixel) at a given latitude and zoom level,
+     * scaled by the latitude's cosine to account for mercator distortion.
+     */
+    pub fn ground_resolution(&self, lat: f64, zoom: f64) -> f64 {
+        self.resolution(zoom) * lat.to_radians().cos()
+    }
+
+    /**
+     * Find the smallest integer zoom level (0..=30) whose resolution is less
+     * than or equal to the given meters-per-pixel value. Returns 30 if no
+     * zoom level in that range is fine enough.
+     */
+    pub fn zoom_for_pixel_size(&self, meters_per_pixel: f64) -> u32 {
+        for zoom in 0..=30 {
+            if self.resolution(zoom as f64) <= meters_per_pixel {
+                return zoom;
+            }
+        }
+        30
+    }
+
+    /**
+     * Geodesic distance (meters) and azimuths (degrees, 0-360) between two
+     * lon/lat points on the WGS84 ellipsoid, computed with Vincenty's inverse
+     * formula. Returns `(distance, azimuth1, azimuth2)`, where `azimuth1` is
+     * the azimuth at `a` toward `b` and `azimuth2` is the azimuth at `b` in
+     * the direction of travel away from `a` (not the azimuth back to `a`). For
+     * near-antipodal points that don't converge, returns `NaN` for all three.
+     */
+    pub fn inverse_geodesic(&self, a: LonLatPoint, b: LonLatPoint) -> (f64, f64, f64) {
+        const WGS84_A: f64 = 6378137.0;
+        const WGS84_F: f64 = 1.0 / 298.257223563;
+        let wgs84_b = WGS84_A * (1.0 - WGS84_F);
+
+        let u1 = ((1.0 - WGS84_F) * a.lat.to_radians().tan()).atan();
+        let u2 = ((1.0 - WGS84_F) * b.lat.to_radians().tan()).atan();
+        let l = (b.lon - a.lon).to_radians();
+
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        let mut sin_sigma;
+        let mut cos_sigma;
+        let mut sigma;
+        let mut cos_sq_alpha;
+        let mut cos2_sigma_m;
+
+        let mut converged = false;
+        for _ in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0.0 {
+                // coincident points
+                return (0.0, 0.0, 0.0);
+            }
+            cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+            cos2_sigma_m = if cos_sq_alpha != 0.0 {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+            } else {
+                0.0
+            };
+            let c = WGS84_F / 16.0 * cos_sq_alpha * (4.0 + WGS84_F * (4.0 - 3.0 * cos_sq_alpha));
+            let lambda_prev = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * WGS84_F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos2_sigma_m
+                                + c * cos_sigma * (-1.0 + 2.0 * cos2_sigma_m * cos2_sigma_m)));
+            if (lambda - lambda_prev).abs() < 1e-12 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return (f64::NAN, f64::NAN, f64::NAN);
+        }
+
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+            + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+        .sqrt();
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1.0 - sin_alpha * sin_alpha;
+        let cos2_sigma_m = if cos_sq_alpha != 0.0 {
+            cos_sigma - 2.0 * sin_u1 * sin_u2 / cos_sq_alpha
+        } else {
+            0.0
+        };
+
+        let u_sq = cos_sq_alpha * (WGS84_A * WGS84_A - wgs84_b * wgs84_b) / (wgs84_b * wgs84_b);
+        let big_a =
+            1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+        let delta_sigma = big_b
+            * sin_sigma
+            * (cos2_sigma_m
+                + big_b / 4.0
+                    * (cos_sigma * (-1.0 + 2.0 * cos2_sigma_m * cos2_sigma_m)
+                        - big_b / 6.0
+                            * cos2_sigma_m
+                            * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                            * (-3.0 + 4.0 * cos2_sigma_m * cos2_sigma_m)));
+
+        let distance = wgs84_b * big_a * (sigma - delta_sigma);
+        let azimuth1 = (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+        let azimuth2 = (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+
+        (
+            distance,
+            (azimuth1.to_degrees() + 360.0) % 360.0,
+            (azimuth2.to_degrees() + 360.0) % 360.0,
+        )
+    }
+
+    /**
+     * Convert a lon/lat point and height above the WGS84 ellipsoid (meters)
+     * to earth-centered, earth-fixed (ECEF) cartesian coordinates `(x, y, z)`.
+     */
+    pub fn to_ecef(&self, ll: LonLatPoint, height_m: f64) -> (f64, f64, f64) {
+        const WGS84_A: f64 = 6378137.0;
+        const WGS84_F: f64 = 1.0 / 298.257223563;
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+
+        let phi = ll.lat.to_radians();
+        let lambda = ll.lon.to_radians();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+
+        let n = WGS84_A / (1.0 - e2 * sin_phi * sin_phi).sqrt();
+
+        let x = (n + height_m) * cos_phi * cos_lambda;
+        let y = (n + height_m) * cos_phi * sin_lambda;
+        let z = (n * (1.0 - e2) + height_m) * sin_phi;
+        (x, y, z)
+    }
+
+    /**
+     * Convert ECEF cartesian coordinates `(x, y, z)` back to a lon/lat point
+     * and height above the WGS84 ellipsoid (meters), using Bowring's
+     * non-iterative method. The inverse of `to_ecef`.
+     */
+    pub fn from_ecef(&self, x: f64, y: f64, z: f64) -> (LonLatPoint, f64) {
+        const WGS84_A: f64 = 6378137.0;
+        const WGS84_F: f64 = 1.0 / 298.257223563;
+        let wgs84_b = WGS84_A * (1.0 - WGS84_F);
+        let e2 = 1.0 - (wgs84_b * wgs84_b) / (WGS84_A * WGS84_A);
+        let ep2 = (WGS84_A * WGS84_A - wgs84_b * wgs84_b) / (wgs84_b * wgs84_b);
+
+        let p = (x * x + y * y).sqrt();
+        let lambda = y.atan2(x);
+
+        if p < WGS84_A * 1e-16 {
+            let lat = if z >= 0.0 { 90.0 } else { -90.0 };
+            let height = z.abs() - wgs84_b;
+            return (
+                LonLatPoint {
+                    lon: lambda.to_degrees(),
+                    lat,
+                },
+                height,
+            );
+        }
+
+        let theta = (z * WGS84_A).atan2(p * wgs84_b);
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let phi =
+            (z + ep2 * wgs84_b * sin_theta.powi(3)).atan2(p - e2 * WGS84_A * cos_theta.powi(3));
+
+        let sin_phi = phi.sin();
+        let n = WGS84_A / (1.0 - e2 * sin_phi * sin_phi).sqrt();
+        let height = p / phi.cos() - n;
+
+        (
+            LonLatPoint {
+                lon: lambda.to_degrees(),
+                lat: phi.to_degrees(),
+            },
+            height,
+        )
+    }
+}
+
+/**
+ * An error returned when parsing a quadkey that contains something other
+ * than the digits `0`-`3`.
+ */
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct QuadkeyError {
+    pub digit: char,
+}
+
+impl std::fmt::Display for QuadkeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid quadkey digit: {:?}", self.digit)
+    }
+}
+
+impl std::error::Error for QuadkeyError {}
+
+/**
+ * A single XYZ tile, addressed Google-style (y increasing downward from the
+ * top of the world).
+ */
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Tile {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+impl Tile {
+    /**
+     * Convert this tile to a lon/lat (or, if `srs` is "900913", mercator) bounding box.
+     */
+    pub fn bbox(&self, sm: &SphericalMercator, srs: &str) -> BBox {
+        sm.bbox(self.x, self.y, self.z, false, srs)
+    }
+
+    /**
+     * Convert this tile to a mercator (900913) bounding box.
+     */
+    pub fn bounds_900913(&self, sm: &SphericalMercator) -> BBox {
+        self.bbox(sm, "900913")
+    }
+
+    /**
+     * The tile that contains this tile at `z - 1`, or `None` at zoom 0.
+     */
+    pub fn parent(&self) -> Option<Tile> {
+        if self.z == 0 {
+            return None;
+        }
+        Some(Tile {
+            x: self.x / 2,
+            y: self.y / 2,
+            z: self.z - 1,
+        })
+    }
+
+    /**
+     * The four tiles at `z + 1` that make up this tile, in NW, NE, SW, SE order.
+     */
+    pub fn children(&self) -> [Tile; 4] {
+        let x = self.x * 2;
+        let y = self.y * 2;
+        let z = self.z + 1;
+        [
+            Tile { x, y, z },
+            Tile { x: x + 1, y, z },
+            Tile { x, y: y + 1, z },
+            Tile {
+                x: x + 1,
+                y: y + 1,
+                z,
+            },
+        ]
+    }
+
+    /**
+     * The up-to-8 tiles surrounding this one at the same zoom level, clamped
+     * to the valid `0..2^z` range.
+     */
+    pub fn neighbors(&self) -> Vec<Tile> {
+        let max = 2_i64.pow(self.z);
+        let mut neighbors = Vec::with_capacity(8);
+        for dx in -1i64..=1 {
+            for dy in -1i64..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let nx = self.x as i64 + dx;
+                let ny = self.y as i64 + dy;
+                if nx < 0 || ny < 0 || nx >= max || ny >= max {
+                    continue;
+                }
+                neighbors.push(Tile {
+                    x: nx as u32,
+                    y: ny as u32,
+                    z: self.z,
+                });
+            }
+        }
+        neighbors
+    }
+
+    /**
+     * Flip `y` to convert between Google (y increasing downward) and TMS
+     * (y increasing upward) tile addressing. Applying this twice is a no-op.
+     */
+    pub fn flip_y(&self) -> Tile {
+        Tile {
+            x: self.x,
+            y: (2_u32.pow(self.z) - 1) - self.y,
+            z: self.z,
+        }
+    }
+
+    /**
+     * Encode this tile as a Bing-style quadkey: a base-4 string of length `z`
+     * where each digit is `(bit of x) + 2*(bit of y)`, most significant first.
+     */
+    pub fn quadkey(&self) -> String {
+        let mut key = String::with_capacity(self.z as usize);
+        for i in (0..self.z).rev() {
+            let mask = 1 << i;
+            let mut digit = 0u8;
+            if self.x & mask != 0 {
+                digit += 1;
+            }
+            if self.y & mask != 0 {
+                digit += 2;
+            }
+            key.push((b'0' + digit) as char);
+        }
+        key
+    }
+
+    /**
+     * Decode a quadkey produced by [`Tile::quadkey`] back into a tile.
+     */
+    pub fn from_quadkey(quadkey: &str) -> Result<Tile, QuadkeyError> {
+        let mut x = 0u32;
+        let mut y = 0u32;
+        let z = quadkey.len() as u32;
+        for c in quadkey.chars() {
+            x <<= 1;
+            y <<= 1;
+            match c {
+                '0' => {}
+                '1' => x |= 1,
+                '2' => y |= 1,
+                '3' => {
+                    x |= 1;
+                    y |= 1;
+                }
+                other => return Err(QuadkeyError { digit: other }),
+            }
+        }
+        Ok(Tile { x, y, z })
+    }
+}
+
+/**
+ * An ellipsoidal Transverse Mercator projector, e.g. for UTM or national grid
+ * coordinates. Unlike `SphericalMercator`, this projects onto a reference
+ * ellipsoid rather than a sphere, and is centered on an arbitrary meridian
+ * rather than the one running through 0° longitude.
+ */
+#[derive(Debug, Clone)]
+pub struct TransverseMercator {
+    pub a: f64,
+    pub b: f64,
+    pub lon0: f64,
+    pub k0: f64,
+    pub false_easting: f64,
+    pub false_northing: f64,
+}
+
+impl TransverseMercator {
+    /**
+     * Create a new projector for an ellipsoid with semi-major axis `a` and
+     * semi-minor axis `b`, centered on meridian `lon0` (degrees), with scale
+     * factor `k0` and the given false easting/northing.
+     */
+    pub fn new(
+        a: f64,
+        b: f64,
+        lon0: f64,
+        k0: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Self {
+        TransverseMercator {
+            a,
+            b,
+            lon0,
+            k0,
+            false_easting,
+            false_northing,
+        }
+    }
+
+    /**
+     * Create a projector for a standard UTM zone (1-60) on the WGS84
+     * ellipsoid, northern or southern hemisphere.
+     */
+    pub fn utm_zone(zone: u32, northern: bool) -> Self {
+        TransverseMercator::new(
+            6378137.0,
+            6356752.314245,
+            (zone as f64) * 6.0 - 183.0,
+            0.9996,
+            500000.0,
+            if northern { 0.0 } else { 10000000.0 },
+        )
+    }
+
+    fn e2(&self) -> f64 {
+        1.0 - (self.b * self.b) / (self.a * self.a)
+    }
+
+    /**
+     * Project a lon/lat point (degrees) to easting/northing (meters).
+     */
+    pub fn forward(&self, ll: LonLatPoint) -> XYPoint {
+        let e2 = self.e2();
+        let ep2 = e2 / (1.0 - e2);
+        let phi = ll.lat.to_radians();
+        let lambda = ll.lon.to_radians();
+        let lambda0 = self.lon0.to_radians();
+
+        let sin_phi = phi.sin();
+        let cos_phi = phi.cos();
+        let tan_phi = phi.tan();
+
+        let n = self.a / (1.0 - e2 * sin_phi * sin_phi).sqrt();
+        let t = tan_phi * tan_phi;
+        let c = ep2 * cos_phi * cos_phi;
+        let ax = cos_phi * (lambda - lambda0);
+
+        let m = self.a
+            * ((1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0) * phi
+                - (3.0 * e2 / 8.0 + 3.0 * e2.powi(2) / 32.0 + 45.0 * e2.powi(3) / 1024.0)
+                    * (2.0 * phi).sin()
+                + (15.0 * e2.powi(2) / 256.0 + 45.0 * e2.powi(3) / 1024.0) * (4.0 * phi).sin()
+                - (35.0 * e2.powi(3) / 3072.0) * (6.0 * phi).sin());
+
+        let x = self.k0
+            * n
+            * (ax
+                + (1.0 - t + c) * ax.powi(3) / 6.0
+                + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * ax.powi(5) / 120.0)
+            + self.false_easting;
+        let y = self.k0
+            * (m + n
+                * tan_phi
+                * (ax.powi(2) / 2.0
+                    + (5.0 - t + 9.0 * c + 4.0 * c * c) * ax.powi(4) / 24.0
+                    + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * ax.powi(6) / 720.0))
+            + self.false_northing;
+
+        XYPoint { x, y }
+    }
+
+    /**
+     * Project an easting/northing point (meters) back to lon/lat (degrees).
+     * The inverse of `forward`.
+     */
+    pub fn inverse(&self, xy: XYPoint) -> LonLatPoint {
+        let e2 = self.e2();
+        let ep2 = e2 / (1.0 - e2);
+        let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+        let lambda0 = self.lon0.to_radians();
+
+        let m = (xy.y - self.false_northing) / self.k0;
+        let mu =
+            m / (self.a * (1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0));
+
+        let phi1 = mu
+            + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+            + (21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+            + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+            + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+        let sin_phi1 = phi1.sin();
+        let cos_phi1 = phi1.cos();
+        let tan_phi1 = phi1.tan();
+
+        let n1 = self.a / (1.0 - e2 * sin_phi1 * sin_phi1).sqrt();
+        let t1 = tan_phi1 * tan_phi1;
+        let c1 = ep2 * cos_phi1 * cos_phi1;
+        let r1 = self.a * (1.0 - e2) / (1.0 - e2 * sin_phi1 * sin_phi1).powf(1.5);
+        let d = (xy.x - self.false_easting) / (n1 * self.k0);
+
+        let phi = phi1
+            - (n1 * tan_phi1 / r1)
+                * (d.powi(2) / 2.0
+                    - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1 - 9.0 * ep2) * d.powi(4) / 24.0
+                    + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1 * t1
+                        - 252.0 * ep2
+                        - 3.0 * c1 * c1)
+                        * d.powi(6)
+                        / 720.0);
+        let lambda = lambda0
+            + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+                + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1 * c1 + 8.0 * ep2 + 24.0 * t1 * t1)
+                    * d.powi(5)
+                    / 120.0)
+                / cos_phi1;
+
+        LonLatPoint {
+            lon: lambda.to_degrees(),
+            lat: phi.to_degrees(),
+        }
+    }
+}
+
+/**
+ * An error returned when a degree value falls outside the range a
+ * `GeoCoord` can represent.
+ */
+#[derive(Debug, PartialEq, Clone)]
+pub struct CoordRangeError {
+    pub value: f64,
+    pub max_abs: f64,
+}
+
+impl std::fmt::Display for CoordRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} is outside the valid range of +/-{} degrees",
+            self.value, self.max_abs
+        )
+    }
+}
+
+impl std::error::Error for CoordRangeError {}
+
+/**
+ * A fixed-point encoding of a single longitude or latitude degree value,
+ * scaled onto the full `i32` range. `i32::MIN` is reserved as an explicit
+ * "invalid" sentinel, so valid coordinates always compare and hash
+ * deterministically against each other.
+ */
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct GeoCoord(i32);
+
+impl GeoCoord {
+    /**
+     * The reserved sentinel value for "no coordinate".
+     */
+    pub const INVALID: GeoCoord = GeoCoord(i32::MIN);
+
+    /**
+     * Encode a degree value in `-max_abs..=max_abs` as a `GeoCoord`.
+     */
+    pub fn from_degrees(value: f64, max_abs: f64) -> Result<GeoCoord, CoordRangeError> {
+        if !(value.is_finite() && value >= -max_abs && value <= max_abs) {
+            return Err(CoordRangeError { value, max_abs });
+        }
+        let scaled = (value / max_abs) * (i32::MAX as f64);
+        Ok(GeoCoord(scaled.round() as i32))
+    }
+
+    /**
+     * Decode this `GeoCoord` back to a degree value in `-max_abs..=max_abs`.
+     */
+    pub fn to_degrees(self, max_abs: f64) -> f64 {
+        (self.0 as f64 / i32::MAX as f64) * max_abs
+    }
+
+    /**
+     * `false` if this is the reserved `GeoCoord::INVALID` sentinel.
+     */
+    pub fn is_valid(&self) -> bool {
+        *self != GeoCoord::INVALID
+    }
+
+    /**
+     * The raw fixed-point representation.
+     */
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+}
+
+/**
+ * A lon/lat point encoded as a pair of fixed-point `GeoCoord`s. Unlike
+ * `LonLatPoint`, this is `Eq`/`Ord`/`Hash`, so it can key hash maps and sort
+ * deterministically, and takes half the storage of two `f64`s.
+ */
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct FixedLonLatPoint {
+    pub lon: GeoCoord,
+    pub lat: GeoCoord,
 }
 
 #[cfg(test)]
@@ -593,4 +1225,207 @@ mod tests {
         assert_eq!(round(with_int.lon), round(with_float.lon));
         assert_eq!(round(with_int.lat), round(with_float.lat));
     }
+
+    #[test]
+    fn test_resolution() {
+        let sm = SphericalMercator::new();
+        assert_eq!(sm.resolution(0.0), 156543.03392804097);
+        assert_eq!(sm.resolution(9.0), 305.748113140705);
+        // each zoom level halves the resolution
+        assert_eq!(sm.resolution(5.0), sm.resolution(4.0) / 2.0);
+    }
+
+    #[test]
+    fn test_ground_resolution() {
+        let sm = SphericalMercator::new();
+        // at the equator ground resolution matches resolution exactly
+        assert_eq!(sm.ground_resolution(0.0, 9.0), sm.resolution(9.0));
+        // resolution tightens towards the poles
+        assert!(sm.ground_resolution(60.0, 9.0) < sm.resolution(9.0));
+    }
+
+    #[test]
+    fn test_zoom_for_pixel_size() {
+        let sm = SphericalMercator::new();
+        assert_eq!(sm.zoom_for_pixel_size(156543.03392804097), 0);
+        assert_eq!(sm.zoom_for_pixel_size(1_000_000.0), 0);
+        assert_eq!(sm.zoom_for_pixel_size(300.0), 10);
+        assert_eq!(sm.zoom_for_pixel_size(0.0), 30);
+    }
+
+    #[test]
+    fn test_tile_bbox() {
+        let sm = SphericalMercator::new();
+        let tile = Tile { x: 0, y: 0, z: 1 };
+        assert_eq!(tile.bbox(&sm, "WGS84"), sm.bbox(0, 0, 1, false, "WGS84"));
+    }
+
+    #[test]
+    fn test_tile_parent_children() {
+        let tile = Tile { x: 3, y: 5, z: 4 };
+        let parent = tile.parent().unwrap();
+        assert_eq!(parent, Tile { x: 1, y: 2, z: 3 });
+        assert!(parent.children().contains(&tile));
+        assert_eq!(Tile { x: 0, y: 0, z: 0 }.parent(), None);
+    }
+
+    #[test]
+    fn test_tile_neighbors() {
+        let tile = Tile { x: 0, y: 0, z: 1 };
+        let neighbors = tile.neighbors();
+        // top-left corner tile only has 3 valid neighbors within 0..2
+        assert_eq!(neighbors.len(), 3);
+        assert!(neighbors.contains(&Tile { x: 1, y: 1, z: 1 }));
+    }
+
+    #[test]
+    fn test_tile_flip_y() {
+        let tile = Tile { x: 1, y: 1, z: 2 };
+        let flipped = tile.flip_y();
+        assert_eq!(flipped, Tile { x: 1, y: 2, z: 2 });
+        assert_eq!(flipped.flip_y(), tile);
+    }
+
+    #[test]
+    fn test_tile_quadkey() {
+        let tile = Tile { x: 3, y: 5, z: 3 };
+        let quadkey = tile.quadkey();
+        assert_eq!(quadkey, "213");
+        assert_eq!(Tile::from_quadkey(&quadkey).unwrap(), tile);
+        assert_eq!(
+            Tile::from_quadkey("031a").unwrap_err(),
+            QuadkeyError { digit: 'a' }
+        );
+    }
+
+    #[test]
+    fn test_transverse_mercator_utm_zone() {
+        // Zone 33N spans 12°E-18°E, so its central meridian is 15°E.
+        let tm = TransverseMercator::utm_zone(33, true);
+        assert_eq!(tm.lon0, 15.0);
+
+        let xy = tm.forward(LonLatPoint {
+            lon: 15.0,
+            lat: 0.0,
+        });
+        // on the central meridian at the equator, easting is exactly the false easting
+        assert_eq!(xy.x.round(), 500000.0);
+        assert_eq!(xy.y.round(), 0.0);
+    }
+
+    #[test]
+    fn test_transverse_mercator_roundtrip() {
+        let tm = TransverseMercator::utm_zone(33, true);
+        let ll = LonLatPoint {
+            lon: 16.25,
+            lat: 48.2,
+        };
+        let xy = tm.forward(ll.clone());
+        let round_tripped = tm.inverse(xy);
+        assert!((round_tripped.lon - ll.lon).abs() < 1e-7);
+        assert!((round_tripped.lat - ll.lat).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_inverse_geodesic() {
+        let sm = SphericalMercator::new();
+        // Flinders Peak to Buninyong, the classic Vincenty worked example.
+        let flinders_peak = LonLatPoint {
+            lon: 144.42487888888888,
+            lat: -37.95103341666667,
+        };
+        let buninyong = LonLatPoint {
+            lon: 143.92649552777777,
+            lat: -37.65282216666666,
+        };
+        let (distance, azimuth1, azimuth2) = sm.inverse_geodesic(flinders_peak, buninyong);
+        assert!((distance - 54972.97567).abs() < 1e-2);
+        assert!((azimuth1 - 306.867453).abs() < 1e-5);
+        assert!((azimuth2 - 307.172931).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_inverse_geodesic_coincident() {
+        let sm = SphericalMercator::new();
+        let p = LonLatPoint {
+            lon: 10.0,
+            lat: 20.0,
+        };
+        assert_eq!(sm.inverse_geodesic(p.clone(), p), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_ecef_roundtrip() {
+        let sm = SphericalMercator::new();
+        let ll = LonLatPoint {
+            lon: 139.767125,
+            lat: 35.681236,
+        };
+        let (x, y, z) = sm.to_ecef(ll.clone(), 40.0);
+        let (round_tripped, height) = sm.from_ecef(x, y, z);
+        assert!((round_tripped.lon - ll.lon).abs() < 1e-9);
+        assert!((round_tripped.lat - ll.lat).abs() < 1e-9);
+        assert!((height - 40.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ecef_pole() {
+        let sm = SphericalMercator::new();
+        let north_pole = LonLatPoint {
+            lon: 0.0,
+            lat: 90.0,
+        };
+        let (x, y, z) = sm.to_ecef(north_pole, 0.0);
+        assert!(x.abs() < 1e-6);
+        assert!(y.abs() < 1e-6);
+        assert!(z > 0.0);
+        let (ll, height) = sm.from_ecef(x, y, z);
+        assert_eq!(ll.lat, 90.0);
+        assert!(height.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_geo_coord_roundtrip() {
+        let ll = LonLatPoint {
+            lon: 139.767125,
+            lat: 35.681236,
+        };
+        let fixed = ll.to_fixed().unwrap();
+        let round_tripped = LonLatPoint::from_fixed(fixed);
+        assert!((round_tripped.lon - ll.lon).abs() < 1e-7);
+        assert!((round_tripped.lat - ll.lat).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_geo_coord_out_of_range() {
+        let ll = LonLatPoint {
+            lon: 250.0,
+            lat: 3.0,
+        };
+        assert_eq!(
+            ll.to_fixed().unwrap_err(),
+            CoordRangeError {
+                value: 250.0,
+                max_abs: 180.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_geo_coord_is_valid() {
+        assert!(!GeoCoord::INVALID.is_valid());
+        assert!(GeoCoord::from_degrees(0.0, 180.0).unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_geo_coord_ord() {
+        let a = GeoCoord::from_degrees(-10.0, 90.0).unwrap();
+        let b = GeoCoord::from_degrees(10.0, 90.0).unwrap();
+        assert!(a < b);
+
+        let mut set = std::collections::HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        assert_eq!(set.len(), 2);
+    }
 }